@@ -0,0 +1,273 @@
+//! serde `Deserializer` that drives serde's `Visitor` API off the existing streaming parser.
+//!
+//! A RESP frame is self-describing, so most methods forward to [`deserialize_any`], the way
+//! `serde_json` does for its `Value`-shaped input. Strings borrow directly from the input
+//! buffer: valid UTF-8 bulk/simple strings are handed to the visitor via
+//! `visit_borrowed_str`, everything else via `visit_borrowed_bytes`.
+//!
+//! [`deserialize_any`]: Deserializer::deserialize_any
+
+use crate::value::{Error, Slice, Value};
+use serde::de::{self, Visitor};
+use serde::{Deserialize, Deserializer as _};
+use std::fmt;
+
+impl de::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Error::Custom(msg.to_string())
+    }
+}
+
+/// Deserializes `T` from the first complete RESP frame in `buf`.
+pub fn from_slice<'de, T>(buf: &'de [u8]) -> Result<T, Error>
+where
+    T: Deserialize<'de>,
+{
+    let (_, value) = Value::parse(buf)?;
+    T::deserialize(Deserializer(value))
+}
+
+struct Deserializer<'de>(Value<'de>);
+
+fn visit_bytes_or_str<'de, V: Visitor<'de>>(bytes: Slice<'de>, visitor: V) -> Result<V::Value, Error> {
+    match std::str::from_utf8(bytes) {
+        Ok(s) => visitor.visit_borrowed_str(s),
+        Err(_) => visitor.visit_borrowed_bytes(bytes),
+    }
+}
+
+impl<'de> de::Deserializer<'de> for Deserializer<'de> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.0 {
+            Value::Null | Value::BulkString(None) | Value::Array(None) => visitor.visit_unit(),
+            Value::Boolean(b) => visitor.visit_bool(b),
+            Value::Integer(i) => visitor.visit_i64(i),
+            Value::Double(d) => visitor.visit_f64(d),
+            Value::SimpleString(s)
+            | Value::Error(s)
+            | Value::BulkString(Some(s))
+            | Value::BulkError(s)
+            | Value::BigNumber(s) => visit_bytes_or_str(s, visitor),
+            Value::VerbatimString { data, .. } => visit_bytes_or_str(data, visitor),
+            Value::Array(Some(items)) | Value::Set(items) | Value::Push(items) => {
+                visitor.visit_seq(SeqAccess {
+                    iter: items.into_iter(),
+                })
+            }
+            Value::Map(pairs) => visitor.visit_map(MapAccess {
+                iter: pairs.into_iter(),
+                value: None,
+            }),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.0 {
+            Value::Null | Value::BulkString(None) | Value::Array(None) => visitor.visit_none(),
+            value => visitor.visit_some(Deserializer(value)),
+        }
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        match self.0 {
+            Value::Array(Some(mut items)) if items.len() == 2 => {
+                let payload = items.pop().unwrap();
+                let tag = items.pop().unwrap();
+                visitor.visit_enum(EnumAccess {
+                    tag,
+                    payload: Some(payload),
+                })
+            }
+            tag => visitor.visit_enum(EnumAccess { tag, payload: None }),
+        }
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct identifier ignored_any
+    }
+}
+
+struct SeqAccess<'de> {
+    iter: std::vec::IntoIter<Value<'de>>,
+}
+
+impl<'de> de::SeqAccess<'de> for SeqAccess<'de> {
+    type Error = Error;
+
+    fn next_element_seed<T: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Error> {
+        match self.iter.next() {
+            Some(value) => seed.deserialize(Deserializer(value)).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        let (lower, upper) = self.iter.size_hint();
+        upper.or(Some(lower))
+    }
+}
+
+struct MapAccess<'de> {
+    iter: std::vec::IntoIter<(Value<'de>, Value<'de>)>,
+    value: Option<Value<'de>>,
+}
+
+impl<'de> de::MapAccess<'de> for MapAccess<'de> {
+    type Error = Error;
+
+    fn next_key_seed<K: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Error> {
+        match self.iter.next() {
+            Some((key, value)) => {
+                self.value = Some(value);
+                seed.deserialize(Deserializer(key)).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: de::DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+        let value = self
+            .value
+            .take()
+            .expect("next_value_seed called before next_key_seed");
+        seed.deserialize(Deserializer(value))
+    }
+}
+
+struct EnumAccess<'de> {
+    tag: Value<'de>,
+    payload: Option<Value<'de>>,
+}
+
+impl<'de> de::EnumAccess<'de> for EnumAccess<'de> {
+    type Error = Error;
+    type Variant = VariantAccess<'de>;
+
+    fn variant_seed<V: de::DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Self::Variant), Error> {
+        let variant = seed.deserialize(Deserializer(self.tag))?;
+        Ok((variant, VariantAccess { payload: self.payload }))
+    }
+}
+
+struct VariantAccess<'de> {
+    payload: Option<Value<'de>>,
+}
+
+impl<'de> de::VariantAccess<'de> for VariantAccess<'de> {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<(), Error> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T: de::DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, Error> {
+        let payload = self
+            .payload
+            .ok_or_else(|| Error::Custom("missing enum payload".into()))?;
+        seed.deserialize(Deserializer(payload))
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value, Error> {
+        let payload = self
+            .payload
+            .ok_or_else(|| Error::Custom("missing enum payload".into()))?;
+        Deserializer(payload).deserialize_any(visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        let payload = self
+            .payload
+            .ok_or_else(|| Error::Custom("missing enum payload".into()))?;
+        Deserializer(payload).deserialize_any(visitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ser::to_vec;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    enum Shape {
+        Unit,
+        Newtype(i32),
+        Tuple(i32, i32),
+        Struct { w: i32, h: i32 },
+    }
+
+    #[test]
+    fn round_trip_struct() {
+        let point = Point { x: 1, y: 2 };
+        let bytes = to_vec(&point).unwrap();
+        assert_eq!(from_slice::<Point>(&bytes).unwrap(), point);
+    }
+
+    #[test]
+    fn round_trip_enum_variants() {
+        let unit = Shape::Unit;
+        assert_eq!(from_slice::<Shape>(&to_vec(&unit).unwrap()).unwrap(), unit);
+
+        let newtype = Shape::Newtype(7);
+        assert_eq!(
+            from_slice::<Shape>(&to_vec(&newtype).unwrap()).unwrap(),
+            newtype
+        );
+
+        let tuple = Shape::Tuple(1, 2);
+        assert_eq!(
+            from_slice::<Shape>(&to_vec(&tuple).unwrap()).unwrap(),
+            tuple
+        );
+
+        let strct = Shape::Struct { w: 3, h: 4 };
+        assert_eq!(
+            from_slice::<Shape>(&to_vec(&strct).unwrap()).unwrap(),
+            strct
+        );
+    }
+
+    #[test]
+    fn round_trip_option() {
+        let bytes = to_vec(&Some(42i64)).unwrap();
+        assert_eq!(from_slice::<Option<i64>>(&bytes).unwrap(), Some(42));
+
+        let bytes = to_vec(&None::<i64>).unwrap();
+        assert_eq!(from_slice::<Option<i64>>(&bytes).unwrap(), None);
+    }
+
+    #[test]
+    fn round_trip_nested_vec() {
+        let value: Vec<Vec<i64>> = vec![vec![1, 2], vec![3], vec![]];
+        let bytes = to_vec(&value).unwrap();
+        assert_eq!(from_slice::<Vec<Vec<i64>>>(&bytes).unwrap(), value);
+    }
+}
@@ -0,0 +1,98 @@
+//! A pull [`Decoder`] that reads RESP frames from any `io::Read`.
+
+use crate::owned::Value;
+use crate::parser::Parser;
+use crate::Error;
+use std::io::BufRead;
+
+/// Reads owned [`Value`]s out of a buffered reader, refilling an incremental [`Parser`]
+/// from the reader whenever the bytes seen so far only hold a partial frame.
+pub struct Decoder<R> {
+    reader: R,
+    parser: Parser,
+}
+
+impl<R: BufRead> Decoder<R> {
+    pub fn new(reader: R) -> Self {
+        Decoder {
+            reader,
+            parser: Parser::new(),
+        }
+    }
+
+    /// Decodes the next value from the stream, blocking on reads from the underlying
+    /// reader as needed. Returns `Err(Error::Eof)` once the reader is exhausted and no
+    /// partial frame is buffered.
+    pub fn decode(&mut self) -> Result<Value, Error> {
+        loop {
+            if let Some(value) = self.parser.try_next()? {
+                return Ok(value);
+            }
+            let had_data = !self.parser.is_idle();
+            let mut chunk = [0u8; 4096];
+            let n = self.reader.read(&mut chunk).map_err(Error::Io)?;
+            if n == 0 {
+                return Err(if had_data { Error::NeedMoreData } else { Error::Eof });
+            }
+            self.parser.feed(&chunk[..n]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::BufReader;
+
+    #[test]
+    fn decode_single_value() {
+        let data = b"$5\r\nhello\r\n";
+        let mut decoder = Decoder::new(BufReader::new(data.as_ref()));
+        assert_eq!(decoder.decode().unwrap(), Value::Bulk("hello".to_string()));
+        assert!(decoder.decode().is_err());
+    }
+
+    #[test]
+    fn decode_multiple_values() {
+        let data = b":1\r\n+OK\r\n";
+        let mut decoder = Decoder::new(BufReader::new(data.as_ref()));
+        assert_eq!(decoder.decode().unwrap(), Value::Integer(1));
+        assert_eq!(decoder.decode().unwrap(), Value::String("OK".to_string()));
+        assert!(decoder.decode().is_err());
+    }
+
+    #[test]
+    fn decode_chunked_reads() {
+        struct Slow<'a> {
+            remaining: &'a [u8],
+        }
+        impl<'a> std::io::Read for Slow<'a> {
+            fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+                let n = 1.min(buf.len()).min(self.remaining.len());
+                buf[..n].copy_from_slice(&self.remaining[..n]);
+                self.remaining = &self.remaining[n..];
+                Ok(n)
+            }
+        }
+
+        let data = b"$5\r\nhello\r\n";
+        let mut decoder = Decoder::new(BufReader::new(Slow { remaining: data }));
+        assert_eq!(decoder.decode().unwrap(), Value::Bulk("hello".to_string()));
+    }
+
+    #[test]
+    fn decode_propagates_io_error() {
+        struct Broken;
+        impl std::io::Read for Broken {
+            fn read(&mut self, _buf: &mut [u8]) -> std::io::Result<usize> {
+                Err(std::io::Error::new(std::io::ErrorKind::ConnectionReset, "reset"))
+            }
+        }
+
+        let mut decoder = Decoder::new(BufReader::new(Broken));
+        match decoder.decode() {
+            Err(Error::Io(e)) => assert_eq!(e.kind(), std::io::ErrorKind::ConnectionReset),
+            other => panic!("expected Error::Io, got {:?}", other),
+        }
+    }
+}
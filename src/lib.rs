@@ -1,7 +1,16 @@
 //! RESP(Redis Serialization Protocol) Serialization for Rust.
 
+mod decoder;
+#[cfg(feature = "serde")]
+pub mod de;
+mod owned;
 mod parser;
+#[cfg(feature = "serde")]
+pub mod ser;
 mod serialize;
-mod value;
+pub mod value;
 
-pub use value::{Error, Value};
+pub use decoder::Decoder;
+pub use owned::Value;
+pub use parser::Parser;
+pub use value::Error;
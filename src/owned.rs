@@ -0,0 +1,176 @@
+//! Owned, `'static` RESP value.
+//!
+//! An owned counterpart to [`value::Value`](crate::value::Value), which borrows from the
+//! input buffer instead.
+
+use crate::value;
+use bytes::{BufMut, BytesMut};
+
+/// An owned counterpart to [`value::Value`](crate::value::Value).
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Null,
+    NullArray,
+    String(String),
+    Error(String),
+    Integer(i64),
+    Bulk(String),
+    Array(Vec<Value>),
+}
+
+impl Value {
+    /// Encode the value to a RESP binary buffer.
+    pub fn encode(&self) -> Vec<u8> {
+        let mut buf = BytesMut::new();
+        self.encode_into(&mut buf);
+        buf.to_vec()
+    }
+
+    fn encode_into(&self, buf: &mut BytesMut) {
+        match self {
+            Value::Null => buf.put_slice(b"$-1\r\n"),
+            Value::NullArray => buf.put_slice(b"*-1\r\n"),
+            Value::String(s) => {
+                buf.put_u8(b'+');
+                buf.put_slice(s.as_bytes());
+                buf.put_slice(b"\r\n");
+            }
+            Value::Error(e) => {
+                buf.put_u8(b'-');
+                buf.put_slice(e.as_bytes());
+                buf.put_slice(b"\r\n");
+            }
+            Value::Integer(i) => {
+                buf.put_u8(b':');
+                buf.put_slice(i.to_string().as_bytes());
+                buf.put_slice(b"\r\n");
+            }
+            Value::Bulk(s) => {
+                buf.put_u8(b'$');
+                buf.put_slice(s.len().to_string().as_bytes());
+                buf.put_slice(b"\r\n");
+                buf.put_slice(s.as_bytes());
+                buf.put_slice(b"\r\n");
+            }
+            Value::Array(items) => {
+                buf.put_u8(b'*');
+                buf.put_slice(items.len().to_string().as_bytes());
+                buf.put_slice(b"\r\n");
+                for item in items {
+                    item.encode_into(buf);
+                }
+            }
+        }
+    }
+}
+
+impl<'a> From<value::Value<'a>> for Value {
+    fn from(value: value::Value<'a>) -> Self {
+        match value {
+            value::Value::SimpleString(s) => Value::String(String::from_utf8_lossy(s).into_owned()),
+            value::Value::Error(e) => Value::Error(String::from_utf8_lossy(e).into_owned()),
+            value::Value::Integer(i) => Value::Integer(i),
+            value::Value::BulkString(None) => Value::Null,
+            value::Value::BulkString(Some(s)) => Value::Bulk(String::from_utf8_lossy(s).into_owned()),
+            value::Value::Array(None) => Value::NullArray,
+            value::Value::Array(Some(items)) => {
+                Value::Array(items.into_iter().map(Value::from).collect())
+            }
+            // RESP3 types have no RESP2-shaped owned counterpart yet; fold them into the
+            // closest RESP2 representation rather than losing the frame entirely.
+            value::Value::Null => Value::Null,
+            value::Value::Boolean(b) => Value::Integer(b as i64),
+            value::Value::Double(d) => Value::String(value::format_double(d)),
+            value::Value::BigNumber(n) => Value::String(String::from_utf8_lossy(n).into_owned()),
+            value::Value::BulkError(e) => Value::Error(String::from_utf8_lossy(e).into_owned()),
+            value::Value::VerbatimString { data, .. } => {
+                Value::Bulk(String::from_utf8_lossy(data).into_owned())
+            }
+            value::Value::Map(pairs) => Value::Array(
+                pairs
+                    .into_iter()
+                    .flat_map(|(k, v)| vec![Value::from(k), Value::from(v)])
+                    .collect(),
+            ),
+            value::Value::Set(items) | value::Value::Push(items) => {
+                Value::Array(items.into_iter().map(Value::from).collect())
+            }
+        }
+    }
+}
+
+impl<'a> value::Value<'a> {
+    /// Deep-copies this borrowed value into an owned [`Value`](crate::owned::Value).
+    pub fn to_owned_value(&self) -> Value {
+        Value::from(clone_ref(self))
+    }
+}
+
+/// Clones a borrowed `value::Value` without taking ownership of the original, so
+/// `to_owned_value` can be called through a shared reference.
+fn clone_ref<'a>(value: &value::Value<'a>) -> value::Value<'a> {
+    match value {
+        value::Value::SimpleString(s) => value::Value::SimpleString(s),
+        value::Value::Error(e) => value::Value::Error(e),
+        value::Value::Integer(i) => value::Value::Integer(*i),
+        value::Value::BulkString(s) => value::Value::BulkString(*s),
+        value::Value::Array(items) => {
+            value::Value::Array(items.as_ref().map(|items| items.iter().map(clone_ref).collect()))
+        }
+        value::Value::Null => value::Value::Null,
+        value::Value::Boolean(b) => value::Value::Boolean(*b),
+        value::Value::Double(d) => value::Value::Double(*d),
+        value::Value::BigNumber(n) => value::Value::BigNumber(n),
+        value::Value::BulkError(e) => value::Value::BulkError(e),
+        value::Value::VerbatimString { format, data } => value::Value::VerbatimString {
+            format: *format,
+            data,
+        },
+        value::Value::Map(pairs) => {
+            value::Value::Map(pairs.iter().map(|(k, v)| (clone_ref(k), clone_ref(v))).collect())
+        }
+        value::Value::Set(items) => value::Value::Set(items.iter().map(clone_ref).collect()),
+        value::Value::Push(items) => value::Value::Push(items.iter().map(clone_ref).collect()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_scalars() {
+        assert_eq!(Value::Null.encode(), b"$-1\r\n".to_vec());
+        assert_eq!(Value::NullArray.encode(), b"*-1\r\n".to_vec());
+        assert_eq!(Value::String("OK".to_string()).encode(), b"+OK\r\n".to_vec());
+        assert_eq!(Value::Error("oops".to_string()).encode(), b"-oops\r\n".to_vec());
+        assert_eq!(Value::Integer(42).encode(), b":42\r\n".to_vec());
+        assert_eq!(Value::Bulk("hi".to_string()).encode(), b"$2\r\nhi\r\n".to_vec());
+    }
+
+    #[test]
+    fn encode_array() {
+        let val = Value::Array(vec![Value::Integer(1), Value::Bulk("a".to_string())]);
+        assert_eq!(val.encode(), b"*2\r\n:1\r\n$1\r\na\r\n".to_vec());
+    }
+
+    #[test]
+    fn from_borrowed() {
+        let borrowed = value::Value::Array(Some(vec![
+            value::Value::BulkString(Some(b"foo")),
+            value::Value::BulkString(None),
+        ]));
+        assert_eq!(
+            Value::from(borrowed),
+            Value::Array(vec![Value::Bulk("foo".to_string()), Value::Null])
+        );
+    }
+
+    #[test]
+    fn to_owned_value_keeps_borrow() {
+        let borrowed = value::Value::SimpleString(b"OK");
+        let owned = borrowed.to_owned_value();
+        assert_eq!(owned, Value::String("OK".to_string()));
+        assert_eq!(borrowed, value::Value::SimpleString(b"OK"));
+    }
+}
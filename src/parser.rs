@@ -1,4 +1,5 @@
-use super::value::{Slice, Value};
+use super::value::{Error, Slice, Value};
+use crate::owned::Value as OwnedValue;
 use nom::bytes::streaming::{tag, take};
 use nom::character::streaming::{crlf, digit1};
 use nom::combinator::{map_res, opt};
@@ -72,19 +73,126 @@ fn array(buf: Slice) -> IResult<Slice, Option<Vec<Value>>> {
     }
 }
 
+fn null(buf: Slice) -> IResult<Slice, ()> {
+    let (left, _) = tag(b"_")(buf)?;
+    let (left, _) = crlf(left)?;
+    Ok((left, ()))
+}
+
+fn boolean(buf: Slice) -> IResult<Slice, bool> {
+    let (left, _) = tag(b"#")(buf)?;
+    let (left, flag) = take(1usize)(left)?;
+    let value = match flag[0] {
+        b't' => true,
+        b'f' => false,
+        _ => return Err(nom::Err::Error((left, ErrorKind::OneOf))),
+    };
+    let (left, _) = crlf(left)?;
+    Ok((left, value))
+}
+
+fn double(buf: Slice) -> IResult<Slice, f64> {
+    let (left, _) = tag(b",")(buf)?;
+    let (left, digits) = terminated(not_crlf, crlf)(left)?;
+    let value = std::str::from_utf8(digits)
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .ok_or(nom::Err::Error((left, ErrorKind::Float)))?;
+    Ok((left, value))
+}
+
+fn big_number(buf: Slice) -> IResult<Slice, Slice> {
+    preceded(tag(b"("), terminated(not_crlf, crlf))(buf)
+}
+
+fn bulk_error(buf: Slice) -> IResult<Slice, Slice> {
+    let (left, _) = tag(b"!")(buf)?;
+    let (left, size) = string_integer(left)?;
+    let (left, _) = crlf(left)?;
+    if size < 0 {
+        return Err(nom::Err::Error((left, ErrorKind::LengthValue)));
+    }
+    let (left, payload) = take(size as usize)(left)?;
+    let (left, _) = crlf(left)?;
+    Ok((left, payload))
+}
+
+fn verbatim_string(buf: Slice) -> IResult<Slice, ([u8; 3], Slice)> {
+    let (left, _) = tag(b"=")(buf)?;
+    let (left, total_len) = string_integer(left)?;
+    let (left, _) = crlf(left)?;
+    if total_len < 4 {
+        return Err(nom::Err::Error((left, ErrorKind::LengthValue)));
+    }
+    let (left, format) = take(3usize)(left)?;
+    let (left, _) = tag(b":")(left)?;
+    let data_len = (total_len as usize).saturating_sub(4);
+    let (left, data) = take(data_len)(left)?;
+    let (left, _) = crlf(left)?;
+    let mut format_tag = [0u8; 3];
+    format_tag.copy_from_slice(format);
+    Ok((left, (format_tag, data)))
+}
+
+fn map(buf: Slice) -> IResult<Slice, Vec<(Value, Value)>> {
+    let (left, _) = tag(b"%")(buf)?;
+    let (left, size) = string_integer(left)?;
+    let (left, _) = crlf(left)?;
+    if size <= 0 {
+        return Ok((left, Vec::new()));
+    }
+    let (left, flat): (Slice, Vec<Value>) =
+        many_m_n(size as usize * 2, size as usize * 2, resp_value)(left)?;
+    let mut pairs = Vec::with_capacity(size as usize);
+    let mut iter = flat.into_iter();
+    while let (Some(key), Some(value)) = (iter.next(), iter.next()) {
+        pairs.push((key, value));
+    }
+    Ok((left, pairs))
+}
+
+fn set(buf: Slice) -> IResult<Slice, Vec<Value>> {
+    let (left, _) = tag(b"~")(buf)?;
+    let (left, size) = string_integer(left)?;
+    let (left, _) = crlf(left)?;
+    if size <= 0 {
+        return Ok((left, Vec::new()));
+    }
+    many_m_n(size as usize, size as usize, resp_value)(left)
+}
+
+fn push(buf: Slice) -> IResult<Slice, Vec<Value>> {
+    let (left, _) = tag(b">")(buf)?;
+    let (left, size) = string_integer(left)?;
+    let (left, _) = crlf(left)?;
+    if size <= 0 {
+        return Ok((left, Vec::new()));
+    }
+    many_m_n(size as usize, size as usize, resp_value)(left)
+}
+
+// Dispatches on RESP's leading type byte and calls only the matching parser, so a
+// partial frame (e.g. a `$` whose payload hasn't fully arrived yet) surfaces its real
+// `nom::Err::Incomplete` instead of being swallowed by a chain of failed alternatives.
 fn resp_value(buf: Slice) -> IResult<Slice, Value> {
-    if let Ok((left, output)) = simple_string(buf) {
-        Ok((left, Value::SimpleString(output)))
-    } else if let Ok((left, output)) = error(buf) {
-        Ok((left, Value::Error(output)))
-    } else if let Ok((left, output)) = integer(buf) {
-        Ok((left, Value::Integer(output)))
-    } else if let Ok((left, output)) = bulk_string(buf) {
-        Ok((left, Value::BulkString(output)))
-    } else if let Ok((left, output)) = array(buf) {
-        Ok((left, Value::Array(output)))
-    } else {
-        Err(nom::Err::Error((buf, ErrorKind::Alt)))
+    match buf.first() {
+        Some(b'+') => simple_string(buf).map(|(left, output)| (left, Value::SimpleString(output))),
+        Some(b'-') => error(buf).map(|(left, output)| (left, Value::Error(output))),
+        Some(b':') => integer(buf).map(|(left, output)| (left, Value::Integer(output))),
+        Some(b'$') => bulk_string(buf).map(|(left, output)| (left, Value::BulkString(output))),
+        Some(b'*') => array(buf).map(|(left, output)| (left, Value::Array(output))),
+        Some(b'_') => null(buf).map(|(left, ())| (left, Value::Null)),
+        Some(b'#') => boolean(buf).map(|(left, output)| (left, Value::Boolean(output))),
+        Some(b',') => double(buf).map(|(left, output)| (left, Value::Double(output))),
+        Some(b'(') => big_number(buf).map(|(left, output)| (left, Value::BigNumber(output))),
+        Some(b'!') => bulk_error(buf).map(|(left, output)| (left, Value::BulkError(output))),
+        Some(b'=') => verbatim_string(buf)
+            .map(|(left, (format, data))| (left, Value::VerbatimString { format, data })),
+        Some(b'%') => map(buf).map(|(left, output)| (left, Value::Map(output))),
+        Some(b'~') => set(buf).map(|(left, output)| (left, Value::Set(output))),
+        Some(b'>') => push(buf).map(|(left, output)| (left, Value::Push(output))),
+        Some(_) => Err(nom::Err::Error((buf, ErrorKind::Alt))),
+        None => Err(nom::Err::Incomplete(nom::Needed::Unknown)),
     }
 }
 
@@ -92,6 +200,170 @@ pub fn parse_resp_value(buf: Slice) -> IResult<Slice, Value> {
     resp_value(buf)
 }
 
+// Consumes just an aggregate's type byte + count + CRLF, leaving its elements (if any) for
+// the caller to parse one at a time. Shared by `Parser`'s incremental dispatch below, since
+// `*`, `%`, `~` and `>` all frame their element count identically.
+fn aggregate_header(buf: Slice) -> IResult<Slice, i64> {
+    preceded(take(1usize), terminated(string_integer, crlf))(buf)
+}
+
+/// One step produced by [`Parser::parse_one`]: either a fully parsed leaf value, or the
+/// header of an aggregate whose elements haven't been seen yet.
+enum Step {
+    Value(OwnedValue),
+    StartAggregate(usize),
+}
+
+/// A frame for an aggregate (array/map/set/push) whose elements are still arriving.
+struct Frame {
+    remaining: usize,
+    items: Vec<OwnedValue>,
+}
+
+/// A resumable, incremental RESP parser.
+///
+/// Unlike [`Value::parse`](crate::value::Value::parse), `Parser` keeps an explicit stack of
+/// partially-filled aggregate frames across calls to [`feed`](Parser::feed), so resuming
+/// doesn't require re-scanning from the start of the buffer. Completed values are returned
+/// as [`OwnedValue`](crate::owned::Value) since a frame's elements may span several buffers
+/// that get compacted away as they're consumed.
+#[derive(Default)]
+pub struct Parser {
+    buf: Vec<u8>,
+    pos: usize,
+    stack: Vec<Frame>,
+    consumed: usize,
+}
+
+impl Parser {
+    pub fn new() -> Self {
+        Parser::default()
+    }
+
+    /// Appends more input to the parser's internal buffer.
+    pub fn feed(&mut self, data: &[u8]) {
+        self.buf.extend_from_slice(data);
+    }
+
+    /// The total number of bytes consumed by completed [`try_next`](Parser::try_next)
+    /// calls so far, so the caller can compact whatever buffer it reads `feed` from.
+    pub fn bytes_consumed(&self) -> usize {
+        self.consumed
+    }
+
+    /// Returns `true` if there's no unparsed input buffered and no aggregate frame in
+    /// progress, i.e. the parser is exactly between two top-level values.
+    pub fn is_idle(&self) -> bool {
+        self.stack.is_empty() && self.pos >= self.buf.len()
+    }
+
+    /// Returns the next complete value, or `Ok(None)` if the buffered input only holds a
+    /// partial frame. Progress made while waiting (parsed leaves, opened aggregate frames)
+    /// is retained for the next call to `feed` + `try_next`.
+    pub fn try_next(&mut self) -> Result<Option<OwnedValue>, Error> {
+        loop {
+            let step = match self.parse_one()? {
+                Some(step) => step,
+                None => return Ok(None),
+            };
+            let done = match step {
+                Step::StartAggregate(0) => self.offer(OwnedValue::Array(Vec::new())),
+                Step::StartAggregate(remaining) => {
+                    self.stack.push(Frame {
+                        remaining,
+                        items: Vec::with_capacity(remaining),
+                    });
+                    None
+                }
+                Step::Value(value) => self.offer(value),
+            };
+            if let Some(value) = done {
+                self.compact();
+                return Ok(Some(value));
+            }
+        }
+    }
+
+    // Hands a completed value to the innermost open frame, bubbling completed frames up to
+    // their parent as they fill. Returns the value once there's no frame left to receive it,
+    // i.e. it's ready to be handed back to the caller.
+    fn offer(&mut self, mut value: OwnedValue) -> Option<OwnedValue> {
+        loop {
+            let frame = match self.stack.last_mut() {
+                Some(frame) => frame,
+                None => return Some(value),
+            };
+            frame.items.push(value);
+            frame.remaining -= 1;
+            if frame.remaining > 0 {
+                return None;
+            }
+            let frame = self.stack.pop().unwrap();
+            value = OwnedValue::Array(frame.items);
+        }
+    }
+
+    // Drops the bytes consumed by completed steps from the front of the buffer.
+    fn compact(&mut self) {
+        self.consumed += self.pos;
+        self.buf.drain(0..self.pos);
+        self.pos = 0;
+    }
+
+    // Parses exactly one header or leaf value starting at `self.pos`, without recursing
+    // into an aggregate's elements.
+    fn parse_one(&mut self) -> Result<Option<Step>, Error> {
+        let remaining = &self.buf[self.pos..];
+        match remaining.first() {
+            None => Ok(None),
+            Some(b'*') => match aggregate_header(remaining) {
+                Ok((left, count)) => {
+                    self.pos += remaining.len() - left.len();
+                    Ok(Some(Self::aggregate_step(count)))
+                }
+                Err(nom::Err::Incomplete(_)) => Ok(None),
+                Err(_) => Err(Error::InvalidData),
+            },
+            // Only `*` has a null encoding (`-1`); `~`/`>`/`%` treat a non-positive count as
+            // an empty collection, matching their one-shot counterparts (`set`/`push`/`map`).
+            Some(b'~') | Some(b'>') => match aggregate_header(remaining) {
+                Ok((left, count)) => {
+                    self.pos += remaining.len() - left.len();
+                    Ok(Some(Step::StartAggregate(count.max(0) as usize)))
+                }
+                Err(nom::Err::Incomplete(_)) => Ok(None),
+                Err(_) => Err(Error::InvalidData),
+            },
+            Some(b'%') => match aggregate_header(remaining) {
+                Ok((left, count)) => {
+                    self.pos += remaining.len() - left.len();
+                    Ok(Some(Step::StartAggregate(count.max(0) as usize * 2)))
+                }
+                Err(nom::Err::Incomplete(_)) => Ok(None),
+                Err(_) => Err(Error::InvalidData),
+            },
+            // `frame_len` reports an unterminated line as `NeedMoreData`, whereas the one-shot
+            // line parsers (`+`, `-`, `,`, `(`) reject it as invalid, so scan first.
+            Some(_) => match Value::frame_len(remaining).and_then(|_| Value::parse(remaining)) {
+                Ok((left, value)) => {
+                    self.pos += remaining.len() - left.len();
+                    Ok(Some(Step::Value(value.to_owned_value())))
+                }
+                Err(Error::NeedMoreData) => Ok(None),
+                Err(e) => Err(e),
+            },
+        }
+    }
+
+    fn aggregate_step(count: i64) -> Step {
+        if count < 0 {
+            Step::Value(OwnedValue::NullArray)
+        } else {
+            Step::StartAggregate(count as usize)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -220,4 +492,191 @@ mod tests {
             ))
         );
     }
+
+    #[test]
+    fn test_null() {
+        assert!(null(b"_as\r\n").is_err());
+        assert_eq!(null(b"_\r\n"), Ok((&[][..], ())));
+    }
+
+    #[test]
+    fn test_boolean() {
+        assert!(boolean(b"#x\r\n").is_err());
+        assert_eq!(boolean(b"#t\r\n"), Ok((&[][..], true)));
+        assert_eq!(boolean(b"#f\r\n"), Ok((&[][..], false)));
+    }
+
+    #[test]
+    fn test_double() {
+        assert_eq!(double(b",3.15\r\n"), Ok((&[][..], 3.15)));
+        assert_eq!(double(b",-1\r\n"), Ok((&[][..], -1.0)));
+        assert_eq!(double(b",inf\r\n"), Ok((&[][..], f64::INFINITY)));
+        assert_eq!(double(b",-inf\r\n"), Ok((&[][..], f64::NEG_INFINITY)));
+        assert!(double(b",nan\r\n").unwrap().1.is_nan());
+    }
+
+    #[test]
+    fn test_big_number() {
+        assert_eq!(
+            big_number(b"(3492890328409238509324850943850943825024385\r\n"),
+            Ok((&[][..], b"3492890328409238509324850943850943825024385".as_ref()))
+        );
+    }
+
+    #[test]
+    fn test_bulk_error() {
+        assert!(bulk_error(b"!-1\r\n").is_err());
+        assert_eq!(
+            bulk_error(b"!21\r\nSYNTAX invalid syntax\r\n"),
+            Ok((&[][..], b"SYNTAX invalid syntax".as_ref()))
+        );
+    }
+
+    #[test]
+    fn test_verbatim_string() {
+        assert_eq!(
+            verbatim_string(b"=15\r\ntxt:Some string\r\n"),
+            Ok((&[][..], (*b"txt", b"Some string".as_ref())))
+        );
+        assert!(verbatim_string(b"=-1\r\n").is_err());
+        assert!(verbatim_string(b"=3\r\n").is_err());
+    }
+
+    #[test]
+    fn test_map() {
+        assert_eq!(map(b"%0\r\n"), Ok((b"".as_ref(), Vec::new())));
+        assert_eq!(
+            map(b"%2\r\n+key1\r\n:1\r\n+key2\r\n:2\r\n"),
+            Ok((
+                b"".as_ref(),
+                vec![
+                    (Value::SimpleString(b"key1"), Value::Integer(1)),
+                    (Value::SimpleString(b"key2"), Value::Integer(2)),
+                ]
+            ))
+        );
+    }
+
+    #[test]
+    fn test_set() {
+        assert_eq!(set(b"~0\r\n"), Ok((b"".as_ref(), Vec::new())));
+        assert_eq!(
+            set(b"~2\r\n+a\r\n+b\r\n"),
+            Ok((
+                b"".as_ref(),
+                vec![Value::SimpleString(b"a"), Value::SimpleString(b"b")]
+            ))
+        );
+    }
+
+    #[test]
+    fn test_push() {
+        assert_eq!(push(b">0\r\n"), Ok((b"".as_ref(), Vec::new())));
+        assert_eq!(
+            push(b">1\r\n+message\r\n"),
+            Ok((b"".as_ref(), vec![Value::SimpleString(b"message")]))
+        );
+    }
+
+    #[test]
+    fn test_resp_value_resp3() {
+        assert_eq!(resp_value(b"_\r\n"), Ok((b"".as_ref(), Value::Null)));
+        assert_eq!(
+            resp_value(b"#t\r\n"),
+            Ok((b"".as_ref(), Value::Boolean(true)))
+        );
+        assert_eq!(
+            resp_value(b",1.5\r\n"),
+            Ok((b"".as_ref(), Value::Double(1.5)))
+        );
+    }
+
+    #[test]
+    fn parser_feeds_whole_frame_at_once() {
+        let mut parser = Parser::new();
+        parser.feed(b":1\r\n+OK\r\n");
+        assert_eq!(parser.try_next().unwrap(), Some(OwnedValue::Integer(1)));
+        assert_eq!(
+            parser.try_next().unwrap(),
+            Some(OwnedValue::String("OK".to_string()))
+        );
+        assert_eq!(parser.try_next().unwrap(), None);
+    }
+
+    #[test]
+    fn parser_resumes_byte_by_byte() {
+        let data = b"*3\r\n:1\r\n:2\r\n:3\r\n";
+        let mut parser = Parser::new();
+        let mut result = None;
+        for &byte in data {
+            parser.feed(&[byte]);
+            if let Some(value) = parser.try_next().unwrap() {
+                result = Some(value);
+                break;
+            }
+        }
+        assert_eq!(
+            result,
+            Some(OwnedValue::Array(vec![
+                OwnedValue::Integer(1),
+                OwnedValue::Integer(2),
+                OwnedValue::Integer(3),
+            ]))
+        );
+    }
+
+    #[test]
+    fn parser_resumes_nested_array_across_feeds() {
+        let mut parser = Parser::new();
+        parser.feed(b"*2\r\n*2\r\n:1\r\n");
+        assert_eq!(parser.try_next().unwrap(), None);
+        parser.feed(b":2\r\n$3\r\nfoo\r\n");
+        assert_eq!(
+            parser.try_next().unwrap(),
+            Some(OwnedValue::Array(vec![
+                OwnedValue::Array(vec![OwnedValue::Integer(1), OwnedValue::Integer(2)]),
+                OwnedValue::Bulk("foo".to_string()),
+            ]))
+        );
+    }
+
+    #[test]
+    fn parser_reports_bytes_consumed_and_compacts() {
+        let mut parser = Parser::new();
+        parser.feed(b":1\r\n:2\r\n");
+        assert_eq!(parser.try_next().unwrap(), Some(OwnedValue::Integer(1)));
+        assert_eq!(parser.bytes_consumed(), 4);
+        assert_eq!(parser.try_next().unwrap(), Some(OwnedValue::Integer(2)));
+        assert_eq!(parser.bytes_consumed(), 8);
+    }
+
+    #[test]
+    fn parser_handles_null_array_and_empty_array() {
+        let mut parser = Parser::new();
+        parser.feed(b"*-1\r\n*0\r\n");
+        assert_eq!(parser.try_next().unwrap(), Some(OwnedValue::NullArray));
+        assert_eq!(
+            parser.try_next().unwrap(),
+            Some(OwnedValue::Array(Vec::new()))
+        );
+    }
+
+    #[test]
+    fn parser_treats_non_positive_set_push_map_counts_as_empty() {
+        let mut parser = Parser::new();
+        parser.feed(b"~-1\r\n>-1\r\n%-1\r\n%0\r\n");
+        for _ in 0..4 {
+            assert_eq!(
+                parser.try_next().unwrap(),
+                Some(OwnedValue::Array(Vec::new()))
+            );
+        }
+    }
+
+    #[test]
+    fn parser_errors_on_invalid_data() {
+        let mut parser = Parser::new();
+        parser.feed(b"@nope\r\n");
+        assert!(parser.try_next().is_err());
+    }
 }
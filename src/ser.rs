@@ -0,0 +1,417 @@
+//! serde `Serializer` that encodes Rust values directly as RESP frames.
+//!
+//! Sequences, tuples and structs become a RESP `Array`, strings and byte slices become a
+//! `BulkString`, integers become an `Integer`, `None`/unit become a null `BulkString`, and
+//! maps become a RESP3 `Map`. Container lengths (seq/map) must be known up front since RESP
+//! frames carry their count before their elements.
+
+use crate::value::{format_double, Error};
+use bytes::{BufMut, BytesMut};
+use serde::{ser, Serialize};
+use std::fmt;
+
+impl ser::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Error::Custom(msg.to_string())
+    }
+}
+
+/// Serializes `value` to a RESP byte buffer.
+pub fn to_vec<T>(value: &T) -> Result<Vec<u8>, Error>
+where
+    T: Serialize + ?Sized,
+{
+    let mut output = BytesMut::new();
+    value.serialize(&mut Serializer {
+        output: &mut output,
+    })?;
+    Ok(output.to_vec())
+}
+
+struct Serializer<'a> {
+    output: &'a mut BytesMut,
+}
+
+fn put_bulk_string(output: &mut BytesMut, bytes: &[u8]) {
+    output.put_u8(b'$');
+    output.put_slice(bytes.len().to_string().as_bytes());
+    output.put_slice(b"\r\n");
+    output.put_slice(bytes);
+    output.put_slice(b"\r\n");
+}
+
+fn put_integer(output: &mut BytesMut, value: i64) {
+    output.put_u8(b':');
+    output.put_slice(value.to_string().as_bytes());
+    output.put_slice(b"\r\n");
+}
+
+fn put_array_header(output: &mut BytesMut, len: usize) {
+    output.put_u8(b'*');
+    output.put_slice(len.to_string().as_bytes());
+    output.put_slice(b"\r\n");
+}
+
+fn put_map_header(output: &mut BytesMut, len: usize) {
+    output.put_u8(b'%');
+    output.put_slice(len.to_string().as_bytes());
+    output.put_slice(b"\r\n");
+}
+
+impl<'a, 'b> ser::Serializer for &'b mut Serializer<'a> {
+    type Ok = ();
+    type Error = Error;
+    type SerializeSeq = Self;
+    type SerializeTuple = Self;
+    type SerializeTupleStruct = Self;
+    type SerializeTupleVariant = Self;
+    type SerializeMap = Self;
+    type SerializeStruct = Self;
+    type SerializeStructVariant = Self;
+
+    fn serialize_bool(self, v: bool) -> Result<(), Error> {
+        self.output.put_u8(b'#');
+        self.output.put_u8(if v { b't' } else { b'f' });
+        self.output.put_slice(b"\r\n");
+        Ok(())
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<(), Error> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<(), Error> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<(), Error> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<(), Error> {
+        put_integer(self.output, v);
+        Ok(())
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<(), Error> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<(), Error> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<(), Error> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<(), Error> {
+        if v > i64::MAX as u64 {
+            return Err(Error::Custom("u64 out of range for a RESP Integer".into()));
+        }
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<(), Error> {
+        self.serialize_f64(v as f64)
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<(), Error> {
+        self.output.put_u8(b',');
+        self.output.put_slice(format_double(v).as_bytes());
+        self.output.put_slice(b"\r\n");
+        Ok(())
+    }
+
+    fn serialize_char(self, v: char) -> Result<(), Error> {
+        self.serialize_str(&v.to_string())
+    }
+
+    fn serialize_str(self, v: &str) -> Result<(), Error> {
+        put_bulk_string(self.output, v.as_bytes());
+        Ok(())
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<(), Error> {
+        put_bulk_string(self.output, v);
+        Ok(())
+    }
+
+    fn serialize_none(self) -> Result<(), Error> {
+        self.output.put_slice(b"$-1\r\n");
+        Ok(())
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<(), Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<(), Error> {
+        self.serialize_none()
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<(), Error> {
+        self.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<(), Error> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        put_array_header(self.output, 2);
+        self.serialize_str(variant)?;
+        value.serialize(self)
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Error> {
+        let len =
+            len.ok_or_else(|| Error::Custom("sequence length must be known up front".into()))?;
+        put_array_header(self.output, len);
+        Ok(self)
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Error> {
+        put_array_header(self.output, len);
+        Ok(self)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Error> {
+        put_array_header(self.output, len);
+        Ok(self)
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Error> {
+        put_array_header(self.output, 2);
+        self.serialize_str(variant)?;
+        put_array_header(self.output, len);
+        Ok(self)
+    }
+
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, Error> {
+        let len = len.ok_or_else(|| Error::Custom("map length must be known up front".into()))?;
+        put_map_header(self.output, len);
+        Ok(self)
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStruct, Error> {
+        put_array_header(self.output, len);
+        Ok(self)
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeStructVariant, Error> {
+        put_array_header(self.output, 2);
+        self.serialize_str(variant)?;
+        put_array_header(self.output, len);
+        Ok(self)
+    }
+}
+
+impl<'a, 'b> ser::SerializeSeq for &'b mut Serializer<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl<'a, 'b> ser::SerializeTuple for &'b mut Serializer<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl<'a, 'b> ser::SerializeTupleStruct for &'b mut Serializer<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl<'a, 'b> ser::SerializeTupleVariant for &'b mut Serializer<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl<'a, 'b> ser::SerializeMap for &'b mut Serializer<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), Error> {
+        key.serialize(&mut **self)
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl<'a, 'b> ser::SerializeStruct for &'b mut Serializer<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        _key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+impl<'a, 'b> ser::SerializeStructVariant for &'b mut Serializer<'a> {
+    type Ok = ();
+    type Error = Error;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        _key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        value.serialize(&mut **self)
+    }
+
+    fn end(self) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Serialize;
+
+    #[derive(Serialize)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    #[derive(Serialize)]
+    enum Shape {
+        Unit,
+        Newtype(i32),
+        Tuple(i32, i32),
+        Struct { w: i32, h: i32 },
+    }
+
+    #[test]
+    fn serialize_scalars() {
+        assert_eq!(to_vec(&42i64).unwrap(), b":42\r\n".to_vec());
+        assert_eq!(to_vec("hi").unwrap(), b"$2\r\nhi\r\n".to_vec());
+        assert_eq!(to_vec(&1.5f64).unwrap(), b",1.5\r\n".to_vec());
+        assert_eq!(to_vec(&true).unwrap(), b"#t\r\n".to_vec());
+    }
+
+    #[test]
+    fn serialize_option() {
+        assert_eq!(to_vec(&None::<i64>).unwrap(), b"$-1\r\n".to_vec());
+        assert_eq!(to_vec(&Some(1i64)).unwrap(), b":1\r\n".to_vec());
+    }
+
+    #[test]
+    fn serialize_tuple_and_nested_seq() {
+        assert_eq!(to_vec(&(1i64, 2i64)).unwrap(), b"*2\r\n:1\r\n:2\r\n".to_vec());
+        let nested: Vec<Vec<i64>> = vec![vec![1], vec![2, 3]];
+        assert_eq!(
+            to_vec(&nested).unwrap(),
+            b"*2\r\n*1\r\n:1\r\n*2\r\n:2\r\n:3\r\n".to_vec()
+        );
+    }
+
+    #[test]
+    fn serialize_struct() {
+        let point = Point { x: 1, y: 2 };
+        assert_eq!(to_vec(&point).unwrap(), b"*2\r\n:1\r\n:2\r\n".to_vec());
+    }
+
+    #[test]
+    fn serialize_enum_variants() {
+        assert_eq!(to_vec(&Shape::Unit).unwrap(), b"$4\r\nUnit\r\n".to_vec());
+        assert_eq!(
+            to_vec(&Shape::Newtype(1)).unwrap(),
+            b"*2\r\n$7\r\nNewtype\r\n:1\r\n".to_vec()
+        );
+        assert_eq!(
+            to_vec(&Shape::Tuple(1, 2)).unwrap(),
+            b"*2\r\n$5\r\nTuple\r\n*2\r\n:1\r\n:2\r\n".to_vec()
+        );
+        assert_eq!(
+            to_vec(&Shape::Struct { w: 1, h: 2 }).unwrap(),
+            b"*2\r\n$6\r\nStruct\r\n*2\r\n:1\r\n:2\r\n".to_vec()
+        );
+    }
+}
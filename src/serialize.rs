@@ -1,6 +1,6 @@
 //! RESP serialize
 
-use crate::value::Value;
+use crate::value::{format_double, Value};
 use bytes::{BufMut, BytesMut};
 
 const CRLF_BYTES: &[u8] = b"\r\n";
@@ -8,7 +8,7 @@ const CRLF_BYTES: &[u8] = b"\r\n";
 /// Encodes RESP value to RESP binary buffer.
 /// # Examples
 /// ```
-/// # use self::resp::Value;
+/// # use self::resp::value::Value;
 /// let val = Value::SimpleString(b"OK");
 /// assert_eq!(val.to_vec().as_slice(), &[43, 79, 75, 13, 10]);
 /// ```
@@ -64,6 +64,67 @@ pub fn encode(value: &Value, buf: &mut BytesMut) -> usize {
                 }
             }
         }
+        Value::Null => {
+            buf.put_slice(b"_");
+            buf.put_slice(CRLF_BYTES);
+        }
+        Value::Boolean(val) => {
+            buf.put_u8(b'#');
+            buf.put_u8(if *val { b't' } else { b'f' });
+            buf.put_slice(CRLF_BYTES);
+        }
+        Value::Double(val) => {
+            buf.put_u8(b',');
+            buf.put_slice(format_double(*val).as_bytes());
+            buf.put_slice(CRLF_BYTES);
+        }
+        Value::BigNumber(val) => {
+            buf.put_u8(b'(');
+            buf.put_slice(val);
+            buf.put_slice(CRLF_BYTES);
+        }
+        Value::BulkError(val) => {
+            buf.put_u8(b'!');
+            buf.put_slice(val.len().to_string().as_bytes());
+            buf.put_slice(CRLF_BYTES);
+            buf.put_slice(val);
+            buf.put_slice(CRLF_BYTES);
+        }
+        Value::VerbatimString { format, data } => {
+            buf.put_u8(b'=');
+            let payload_len = format.len() + 1 + data.len();
+            buf.put_slice(payload_len.to_string().as_bytes());
+            buf.put_slice(CRLF_BYTES);
+            buf.put_slice(format);
+            buf.put_u8(b':');
+            buf.put_slice(data);
+            buf.put_slice(CRLF_BYTES);
+        }
+        Value::Map(pairs) => {
+            buf.put_u8(b'%');
+            buf.put_slice(pairs.len().to_string().as_bytes());
+            buf.put_slice(CRLF_BYTES);
+            for (key, val) in pairs {
+                encode(key, buf);
+                encode(val, buf);
+            }
+        }
+        Value::Set(items) => {
+            buf.put_u8(b'~');
+            buf.put_slice(items.len().to_string().as_bytes());
+            buf.put_slice(CRLF_BYTES);
+            for item in items {
+                encode(item, buf);
+            }
+        }
+        Value::Push(items) => {
+            buf.put_u8(b'>');
+            buf.put_slice(items.len().to_string().as_bytes());
+            buf.put_slice(CRLF_BYTES);
+            for item in items {
+                encode(item, buf);
+            }
+        }
     }
 
     let len = buf.len() - initial;
@@ -5,14 +5,44 @@ use crate::parser::parse_resp_value;
 use bytes::BytesMut;
 use std::vec::Vec;
 
+#[derive(Debug)]
 pub enum Error {
     InvalidData,
     NeedMoreData,
+    /// The underlying reader reached EOF with no partial frame buffered, as opposed to
+    /// `NeedMoreData`, which means a frame is in progress and more bytes are expected.
+    Eof,
+    /// A [`Decoder`](crate::Decoder) read failed at the transport level, as opposed to the
+    /// bytes it did receive being malformed RESP.
+    Io(std::io::Error),
+    /// Carries a message from a `serde::de::Error`/`serde::ser::Error::custom` call.
+    #[cfg(feature = "serde")]
+    Custom(String),
 }
 
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Error::InvalidData => write!(f, "invalid RESP data"),
+            Error::NeedMoreData => write!(f, "need more data"),
+            Error::Eof => write!(f, "end of stream"),
+            Error::Io(e) => write!(f, "{}", e),
+            #[cfg(feature = "serde")]
+            Error::Custom(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
 pub type Slice<'a> = &'a [u8];
 
 /// Represents a RESP value, see [Redis Protocol specification](http://redis.io/topics/protocol).
+///
+/// Besides the five RESP2 types, this also covers the RESP3 types introduced by
+/// `HELLO 3`: [`Value::Null`], [`Value::Boolean`], [`Value::Double`], [`Value::BigNumber`],
+/// [`Value::BulkError`], [`Value::VerbatimString`], [`Value::Map`], [`Value::Set`] and
+/// [`Value::Push`]. A single parser/encoder handles both dialects.
 #[derive(Debug, PartialEq)]
 pub enum Value<'a> {
     SimpleString(Slice<'a>),
@@ -20,6 +50,25 @@ pub enum Value<'a> {
     Integer(i64),
     BulkString(Option<Slice<'a>>),
     Array(Option<Vec<Value<'a>>>),
+    /// RESP3 `_\r\n`.
+    Null,
+    /// RESP3 `#t\r\n` / `#f\r\n`.
+    Boolean(bool),
+    /// RESP3 `,3.14\r\n`, also used to encode `inf`, `-inf` and `nan`.
+    Double(f64),
+    /// RESP3 `(3492890328409238509324850943850943825024385\r\n`, kept as its raw digits
+    /// since the value may exceed `i64`.
+    BigNumber(Slice<'a>),
+    /// RESP3 `!21\r\nSYNTAX invalid syntax\r\n`, framed like `BulkString` but never null.
+    BulkError(Slice<'a>),
+    /// RESP3 `=15\r\ntxt:Some string\r\n`.
+    VerbatimString { format: [u8; 3], data: Slice<'a> },
+    /// RESP3 `%2\r\n...\r\n`, a sequence of key-value pairs.
+    Map(Vec<(Value<'a>, Value<'a>)>),
+    /// RESP3 `~3\r\n...\r\n`.
+    Set(Vec<Value<'a>>),
+    /// RESP3 `>2\r\n...\r\n`, an out-of-band message pushed by the server.
+    Push(Vec<Value<'a>>),
 }
 
 impl<'a> Value<'a> {
@@ -34,22 +83,23 @@ impl<'a> Value<'a> {
     /// Returns `true` if the value is a `Null` or `NullArray`. Returns `false` otherwise.
     /// # Examples
     /// ```
-    /// # use self::resp::{Value};
+    /// # use self::resp::value::Value;
     /// assert_eq!(Value::Array(None).is_null(), true);
     /// assert_eq!(Value::BulkString(None).is_null(), true);
+    /// assert_eq!(Value::Null.is_null(), true);
     /// assert_eq!(Value::Integer(123).is_null(), false);
     /// ```
     pub fn is_null(&self) -> bool {
-        match *self {
-            Value::Array(None) | Value::BulkString(None) => true,
-            _ => false,
-        }
+        matches!(
+            *self,
+            Value::Array(None) | Value::BulkString(None) | Value::Null
+        )
     }
 
     /// Returns `true` if the value is a `Error`. Returns `false` otherwise.
     /// # Examples
     /// ```
-    /// # use self::resp::{Value};
+    /// # use self::resp::value::Value;
     /// assert_eq!(Value::SimpleString(b"aa").is_error(), false);
     /// assert_eq!(Value::Error(b"").is_error(), true);
     /// ```
@@ -67,7 +117,7 @@ impl<'a> Value<'a> {
     /// Encode the value to RESP binary buffer.
     /// # Examples
     /// ```
-    /// # use self::resp::{Value};
+    /// # use self::resp::value::Value;
     /// let val = Value::SimpleString("OK正".as_bytes());
     /// assert_eq!(val.to_vec(), vec![43, 79, 75, 230, 173, 163, 13, 10]);
     /// ```
@@ -93,7 +143,182 @@ impl<'a> Value<'a> {
                     + CRLF_LEN
                     + array.iter().map(|s| s.serialize_len()).sum::<usize>()
             }
+            Value::Null => 1 + CRLF_LEN,
+            Value::Boolean(_) => 1 + 1 + CRLF_LEN,
+            Value::Double(d) => 1 + format_double(*d).len() + CRLF_LEN,
+            Value::BigNumber(n) => 1 + n.len() + CRLF_LEN,
+            Value::BulkError(e) => 1 + e.len().to_string().len() + CRLF_LEN + e.len() + CRLF_LEN,
+            Value::VerbatimString { data, .. } => {
+                let payload_len = 3 + 1 + data.len();
+                1 + payload_len.to_string().len() + CRLF_LEN + payload_len + CRLF_LEN
+            }
+            Value::Map(pairs) => {
+                1 + pairs.len().to_string().len()
+                    + CRLF_LEN
+                    + pairs
+                        .iter()
+                        .map(|(k, v)| k.serialize_len() + v.serialize_len())
+                        .sum::<usize>()
+            }
+            Value::Set(items) | Value::Push(items) => {
+                1 + items.len().to_string().len()
+                    + CRLF_LEN
+                    + items.iter().map(|v| v.serialize_len()).sum::<usize>()
+            }
+        }
+    }
+
+    /// Computes the byte length of the first complete RESP frame in `buf`, the same way
+    /// [`serialize_len`](Value::serialize_len) does for an already-parsed `Value`, but
+    /// without building a `Value` tree.
+    pub fn frame_len(buf: Slice) -> Result<usize, Error> {
+        scan_frame_len(buf)
+    }
+
+    /// Computes the total byte length of the first `count` complete RESP frames in `buf`.
+    pub fn frames_len(buf: Slice, count: usize) -> Result<usize, Error> {
+        let mut total = 0;
+        for _ in 0..count {
+            total += scan_frame_len(&buf[total..])?;
         }
+        Ok(total)
+    }
+}
+
+/// An owned, base64-decoded `BulkString` payload, see [`Value::bulk_from_base64`].
+#[cfg(feature = "base64")]
+#[derive(Debug, Clone, PartialEq)]
+pub struct OwnedBulk(Vec<u8>);
+
+#[cfg(feature = "base64")]
+impl OwnedBulk {
+    /// Borrows this payload as a `BulkString`.
+    pub fn as_value(&self) -> Value<'_> {
+        Value::BulkString(Some(&self.0))
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+
+    pub fn into_vec(self) -> Vec<u8> {
+        self.0
+    }
+}
+
+#[cfg(feature = "base64")]
+impl<'a> Value<'a> {
+    /// Decodes a base64 string (with or without trailing `=` padding) into an owned
+    /// `BulkString` payload.
+    pub fn bulk_from_base64(s: &str) -> Result<OwnedBulk, Error> {
+        base64::decode_config(s.trim_end_matches('='), base64::STANDARD_NO_PAD)
+            .map(OwnedBulk)
+            .map_err(|_| Error::InvalidData)
+    }
+
+    /// Renders a `BulkString`'s bytes as base64, or `None` for any other variant.
+    pub fn bulk_to_base64(&self) -> Option<String> {
+        match self {
+            Value::BulkString(Some(s)) => Some(base64::encode(s)),
+            _ => None,
+        }
+    }
+}
+
+fn find_crlf(buf: Slice) -> Option<usize> {
+    buf.windows(2).position(|w| w == b"\r\n")
+}
+
+fn expect_crlf(buf: Slice) -> Result<(), Error> {
+    if buf.len() < 2 {
+        Err(Error::NeedMoreData)
+    } else if &buf[..2] != b"\r\n" {
+        Err(Error::InvalidData)
+    } else {
+        Ok(())
+    }
+}
+
+// Parses a `<digits>\r\n` header line, returning the bytes it spans (digits + CRLF) and
+// the parsed integer.
+fn parse_size_line(buf: Slice) -> Result<(usize, i64), Error> {
+    let line_len = find_crlf(buf).ok_or(Error::NeedMoreData)?;
+    let size = std::str::from_utf8(&buf[..line_len])
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .ok_or(Error::InvalidData)?;
+    Ok((line_len + 2, size))
+}
+
+// Length of a `$`/`!`/`=`-shaped frame body: `<digits>\r\n<payload>\r\n`. `allow_negative`
+// distinguishes `BulkString`'s null form (`$-1\r\n`, no payload) from `BulkError`/
+// `VerbatimString`, which are never null.
+fn scan_sized_payload(rest: Slice, allow_negative: bool) -> Result<usize, Error> {
+    let (header_len, size) = parse_size_line(rest)?;
+    if size < 0 {
+        return if allow_negative {
+            Ok(header_len)
+        } else {
+            Err(Error::InvalidData)
+        };
+    }
+    let total = header_len + size as usize + 2;
+    if rest.len() < total {
+        return Err(Error::NeedMoreData);
+    }
+    Ok(total)
+}
+
+// Length of a `*`/`%`/`~`/`>`-shaped frame body: a count header followed by that many
+// (or, for `%`, twice that many) nested frames. A non-positive count, null or empty alike,
+// has no elements to scan, matching how the parsers for these types treat it.
+fn scan_aggregate(rest: Slice, elements_per_count: usize) -> Result<usize, Error> {
+    let (mut offset, count) = parse_size_line(rest)?;
+    if count <= 0 {
+        return Ok(offset);
+    }
+    for _ in 0..(count as usize * elements_per_count) {
+        offset += scan_frame_len(&rest[offset..])?;
+    }
+    Ok(offset)
+}
+
+fn scan_frame_len(buf: Slice) -> Result<usize, Error> {
+    let (&tag, rest) = buf.split_first().ok_or(Error::NeedMoreData)?;
+    let body_len = match tag {
+        b'+' | b'-' | b':' | b'(' | b',' => find_crlf(rest).ok_or(Error::NeedMoreData)? + 2,
+        b'_' => {
+            expect_crlf(rest)?;
+            2
+        }
+        b'#' => {
+            if rest.is_empty() {
+                return Err(Error::NeedMoreData);
+            }
+            expect_crlf(&rest[1..])?;
+            3
+        }
+        b'$' => scan_sized_payload(rest, true)?,
+        b'!' | b'=' => scan_sized_payload(rest, false)?,
+        b'*' | b'~' | b'>' => scan_aggregate(rest, 1)?,
+        b'%' => scan_aggregate(rest, 2)?,
+        _ => return Err(Error::InvalidData),
+    };
+    Ok(1 + body_len)
+}
+
+/// Formats a RESP3 double, special-casing infinities and NaN per the protocol.
+pub(crate) fn format_double(d: f64) -> String {
+    if d.is_nan() {
+        "nan".to_string()
+    } else if d.is_infinite() {
+        if d.is_sign_negative() {
+            "-inf".to_string()
+        } else {
+            "inf".to_string()
+        }
+    } else {
+        d.to_string()
     }
 }
 
@@ -205,4 +430,160 @@ mod tests {
                 .to_vec()
         );
     }
+
+    #[test]
+    fn enum_encode_resp3_null() {
+        let val = Value::Null;
+        assert_eq!(val.to_vec().as_slice(), b"_\r\n");
+        assert_eq!(val.is_null(), true);
+    }
+
+    #[test]
+    fn enum_encode_boolean() {
+        assert_eq!(Value::Boolean(true).to_vec().as_slice(), b"#t\r\n");
+        assert_eq!(Value::Boolean(false).to_vec().as_slice(), b"#f\r\n");
+    }
+
+    #[test]
+    fn enum_encode_double() {
+        assert_eq!(Value::Double(3.15).to_vec().as_slice(), b",3.15\r\n");
+        assert_eq!(
+            Value::Double(f64::INFINITY).to_vec().as_slice(),
+            b",inf\r\n"
+        );
+        assert_eq!(
+            Value::Double(f64::NEG_INFINITY).to_vec().as_slice(),
+            b",-inf\r\n"
+        );
+        assert_eq!(Value::Double(f64::NAN).to_vec().as_slice(), b",nan\r\n");
+    }
+
+    #[test]
+    fn enum_encode_big_number() {
+        let val = Value::BigNumber(b"3492890328409238509324850943850943825024385");
+        assert_eq!(
+            val.to_vec().as_slice(),
+            b"(3492890328409238509324850943850943825024385\r\n".as_ref()
+        );
+    }
+
+    #[test]
+    fn enum_encode_bulk_error() {
+        let val = Value::BulkError(b"SYNTAX invalid syntax");
+        assert_eq!(
+            val.to_vec().as_slice(),
+            b"!21\r\nSYNTAX invalid syntax\r\n".as_ref()
+        );
+    }
+
+    #[test]
+    fn enum_encode_verbatim_string() {
+        let val = Value::VerbatimString {
+            format: *b"txt",
+            data: b"Some string",
+        };
+        assert_eq!(
+            val.to_vec().as_slice(),
+            b"=15\r\ntxt:Some string\r\n".as_ref()
+        );
+    }
+
+    #[test]
+    fn enum_encode_map() {
+        let val = Value::Map(vec![(
+            Value::SimpleString(b"key"),
+            Value::SimpleString(b"value"),
+        )]);
+        assert_eq!(
+            val.to_vec().as_slice(),
+            b"%1\r\n+key\r\n+value\r\n".as_ref()
+        );
+    }
+
+    #[test]
+    fn enum_encode_set() {
+        let val = Value::Set(vec![Value::Integer(1), Value::Integer(2)]);
+        assert_eq!(val.to_vec().as_slice(), b"~2\r\n:1\r\n:2\r\n".as_ref());
+    }
+
+    #[test]
+    fn enum_encode_push() {
+        let val = Value::Push(vec![Value::SimpleString(b"pubsub")]);
+        assert_eq!(val.to_vec().as_slice(), b">1\r\n+pubsub\r\n".as_ref());
+    }
+
+    #[test]
+    fn frame_len_scalars() {
+        assert_eq!(Value::frame_len(b"+OK\r\n").unwrap(), 5);
+        assert_eq!(Value::frame_len(b":1\r\n").unwrap(), 4);
+        assert_eq!(Value::frame_len(b"$-1\r\n").unwrap(), 5);
+        assert_eq!(Value::frame_len(b"$3\r\nfoo\r\n").unwrap(), 9);
+        assert_eq!(Value::frame_len(b"_\r\n").unwrap(), 3);
+        assert_eq!(Value::frame_len(b"#t\r\n").unwrap(), 4);
+        assert_eq!(Value::frame_len(b"!21\r\nSYNTAX invalid syntax\r\n").unwrap(), 28);
+        assert_eq!(
+            Value::frame_len(b"=15\r\ntxt:Some string\r\n").unwrap(),
+            22
+        );
+    }
+
+    #[test]
+    fn frame_len_ignores_trailing_bytes() {
+        assert_eq!(Value::frame_len(b"*-1\r\nEXTRA").unwrap(), 5);
+        assert_eq!(
+            Value::frame_len(b"*2\r\n$3\r\nfoo\r\n$3\r\nbar\r\nEXTRA").unwrap(),
+            22
+        );
+    }
+
+    #[test]
+    fn frame_len_nested_aggregate() {
+        let buf = b"*2\r\n*3\r\n:1\r\n:2\r\n:3\r\n*2\r\n+Foo\r\n-Bar\r\n";
+        assert_eq!(Value::frame_len(buf).unwrap(), buf.len());
+    }
+
+    #[test]
+    fn frame_len_needs_more_data() {
+        assert!(matches!(
+            Value::frame_len(b"$5\r\nhel"),
+            Err(Error::NeedMoreData)
+        ));
+        assert!(matches!(Value::frame_len(b""), Err(Error::NeedMoreData)));
+        assert!(matches!(
+            Value::frame_len(b"*2\r\n:1\r\n"),
+            Err(Error::NeedMoreData)
+        ));
+    }
+
+    #[test]
+    fn frames_len_multiple() {
+        let buf = b":1\r\n+OK\r\n$3\r\nfoo\r\n";
+        assert_eq!(Value::frames_len(buf, 3).unwrap(), buf.len());
+        assert_eq!(Value::frames_len(buf, 2).unwrap(), b":1\r\n+OK\r\n".len());
+    }
+
+    #[cfg(feature = "base64")]
+    #[test]
+    fn bulk_from_base64_decodes_padded_and_unpadded() {
+        let padded = Value::bulk_from_base64("aGVsbG8=").unwrap();
+        let unpadded = Value::bulk_from_base64("aGVsbG8").unwrap();
+        assert_eq!(padded.as_bytes(), b"hello");
+        assert_eq!(unpadded.as_bytes(), b"hello");
+        assert_eq!(padded.as_value(), Value::BulkString(Some(b"hello")));
+    }
+
+    #[cfg(feature = "base64")]
+    #[test]
+    fn bulk_from_base64_rejects_invalid_input() {
+        assert!(Value::bulk_from_base64("not base64!!").is_err());
+    }
+
+    #[cfg(feature = "base64")]
+    #[test]
+    fn bulk_to_base64_round_trips_and_rejects_non_bulk() {
+        let val = Value::BulkString(Some(b"hello"));
+        assert_eq!(val.bulk_to_base64(), Some("aGVsbG8=".to_string()));
+        assert_eq!(Value::BulkString(None).bulk_to_base64(), None);
+        assert_eq!(Value::Integer(1).bulk_to_base64(), None);
+    }
 }